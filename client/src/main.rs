@@ -1,8 +1,9 @@
 use borsh::BorshSerialize;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
     signature::{read_keypair_file, Keypair, Signer},
     system_program,
     transaction::Transaction,
@@ -21,6 +22,101 @@ struct Args {
     /// The file containing the payer's keypair.
     #[arg(long, default_value_t = payer_default())]
     payer: String,
+
+    /// Use the Token-2022 program instead of classic SPL Token.
+    #[arg(long)]
+    token_2022: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Create a new mint, mint a demo amount to a recipient, and optionally attach metadata.
+    Init {
+        /// The freeze authority to set on the mint, if any.
+        #[arg(long)]
+        freeze_authority: Option<Pubkey>,
+
+        /// The wallet to mint the demo amount into (defaults to the payer).
+        #[arg(long)]
+        recipient: Option<Pubkey>,
+
+        /// The token name to attach as on-chain metadata (requires --symbol and --uri).
+        #[arg(long, requires_all = ["symbol", "uri"])]
+        name: Option<String>,
+
+        /// The token symbol to attach as on-chain metadata (requires --name and --uri).
+        #[arg(long, requires_all = ["name", "uri"])]
+        symbol: Option<String>,
+
+        /// The off-chain URI (e.g. to a JSON blob) to attach as on-chain metadata (requires --name and --symbol).
+        #[arg(long, requires_all = ["name", "symbol"])]
+        uri: Option<String>,
+
+        /// Transfer fee in basis points to charge on every transfer (requires --token-2022 and --maximum-fee).
+        #[arg(long, requires = "maximum_fee")]
+        transfer_fee_basis_points: Option<u16>,
+
+        /// The maximum fee (in the mint's smallest unit) charged per transfer (requires --token-2022 and --transfer-fee-basis-points).
+        #[arg(long, requires = "transfer_fee_basis_points")]
+        maximum_fee: Option<u64>,
+    },
+    /// Freeze a wallet's associated token account for the given mint.
+    Freeze {
+        /// The mint whose associated token account should be frozen.
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// The owner of the associated token account to freeze.
+        #[arg(long)]
+        owner: Pubkey,
+
+        /// The file containing the freeze authority's keypair (defaults to --payer, which
+        /// only works if the mint was initialized with the payer as its freeze authority).
+        #[arg(long)]
+        authority: Option<String>,
+    },
+    /// Thaw a previously frozen associated token account for the given mint.
+    Thaw {
+        /// The mint whose associated token account should be thawed.
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// The owner of the associated token account to thaw.
+        #[arg(long)]
+        owner: Pubkey,
+
+        /// The file containing the freeze authority's keypair (defaults to --payer, which
+        /// only works if the mint was initialized with the payer as its freeze authority).
+        #[arg(long)]
+        authority: Option<String>,
+    },
+    /// Transfer tokens from the payer's associated token account to another wallet.
+    Transfer {
+        /// The mint being transferred.
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// The wallet receiving the tokens.
+        #[arg(long)]
+        to: Pubkey,
+
+        /// The number of tokens (in the mint's smallest unit) to transfer.
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Burn tokens from the payer's associated token account.
+    Burn {
+        /// The mint being burned.
+        #[arg(long)]
+        mint: Pubkey,
+
+        /// The number of tokens (in the mint's smallest unit) to burn.
+        #[arg(long)]
+        amount: u64,
+    },
 }
 
 fn payer_default() -> String {
@@ -33,16 +129,47 @@ fn payer_default() -> String {
 enum SplTokenMint {
     Initialize(InitializeMintArgs),
     Mint(MintToArgs),
+    CreateMetadata(CreateMetadataArgs),
+    Freeze,
+    Thaw,
+    Transfer(TransferArgs),
+    Burn(BurnArgs),
 }
 
 #[derive(BorshSerialize, Debug)]
 struct InitializeMintArgs {
     decimals: u8,
+    freeze_authority: Option<Pubkey>,
+    transfer_fee: Option<TransferFeeArgs>,
+}
+
+#[derive(BorshSerialize, Debug)]
+struct TransferFeeArgs {
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
 }
 
 #[derive(BorshSerialize, Debug)]
 struct MintToArgs {
     amount: u64,
+    recipient: Pubkey,
+}
+
+#[derive(BorshSerialize, Debug)]
+struct CreateMetadataArgs {
+    name: String,
+    symbol: String,
+    uri: String,
+}
+
+#[derive(BorshSerialize, Debug)]
+struct TransferArgs {
+    amount: u64,
+}
+
+#[derive(BorshSerialize, Debug)]
+struct BurnArgs {
+    amount: u64,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -51,15 +178,119 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client = RpcClient::new("http://localhost:8899".to_string());
 
     // Program ID of the deployed program
-    let program_id = read_keypair_file(args.program)?.pubkey();
+    let program_id = read_keypair_file(&args.program)?.pubkey();
 
-    let payer = read_keypair_file(args.payer)?;
-    let mint_account = Keypair::new();
+    let payer = read_keypair_file(&args.payer)?;
     let system_account = system_program::ID;
+    let token_program_id = if args.token_2022 {
+        spl_token_2022::id()
+    } else {
+        spl_token::id()
+    };
+
+    match args.command {
+        Command::Init {
+            freeze_authority,
+            recipient,
+            name,
+            symbol,
+            uri,
+            transfer_fee_basis_points,
+            maximum_fee,
+        } => {
+            let transfer_fee = match (transfer_fee_basis_points, maximum_fee) {
+                (Some(transfer_fee_basis_points), Some(maximum_fee)) => {
+                    if !args.token_2022 {
+                        return Err("--transfer-fee-basis-points requires --token-2022".into());
+                    }
+                    Some(TransferFeeArgs {
+                        transfer_fee_basis_points,
+                        maximum_fee,
+                    })
+                }
+                _ => None,
+            };
+            init(
+                &client,
+                program_id,
+                &payer,
+                system_account,
+                token_program_id,
+                freeze_authority,
+                recipient.unwrap_or(payer.pubkey()),
+                name,
+                symbol,
+                uri,
+                transfer_fee,
+            )
+        }
+        Command::Freeze {
+            mint,
+            owner,
+            authority,
+        } => {
+            let authority = authority.map(read_keypair_file).transpose()?;
+            set_frozen_state(
+                &client,
+                program_id,
+                &payer,
+                authority.as_ref().unwrap_or(&payer),
+                token_program_id,
+                mint,
+                owner,
+                true,
+            )
+        }
+        Command::Thaw {
+            mint,
+            owner,
+            authority,
+        } => {
+            let authority = authority.map(read_keypair_file).transpose()?;
+            set_frozen_state(
+                &client,
+                program_id,
+                &payer,
+                authority.as_ref().unwrap_or(&payer),
+                token_program_id,
+                mint,
+                owner,
+                false,
+            )
+        }
+        Command::Transfer { mint, to, amount } => {
+            transfer(&client, program_id, &payer, token_program_id, mint, to, amount)
+        }
+        Command::Burn { mint, amount } => {
+            burn(&client, program_id, &payer, token_program_id, mint, amount)
+        }
+    }
+}
+
+/// Create a new mint, mint a demo amount to `recipient`, and optionally attach metadata.
+#[allow(clippy::too_many_arguments)]
+fn init(
+    client: &RpcClient,
+    program_id: Pubkey,
+    payer: &Keypair,
+    system_account: Pubkey,
+    token_program_id: Pubkey,
+    freeze_authority: Option<Pubkey>,
+    recipient: Pubkey,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+    transfer_fee: Option<TransferFeeArgs>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mint_account = Keypair::new();
 
     // Fund the payer account on localnet
     client.request_airdrop(&payer.pubkey(), 1_000_000_000)?;
-    let data = SplTokenMint::Initialize(InitializeMintArgs { decimals: 2 });
+    let data = SplTokenMint::Initialize(InitializeMintArgs {
+        decimals: 2,
+        freeze_authority,
+        transfer_fee,
+    });
     let mut buffer: Vec<u8> = Vec::new();
     data.serialize(&mut buffer)?;
     // Create and send the "initialize mint" transaction
@@ -70,25 +301,30 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             AccountMeta::new(mint_account.pubkey(), true),
             AccountMeta::new(payer.pubkey(), true),
             AccountMeta::new_readonly(system_account, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
         ],
     );
 
     let recent_blockhash = client.get_latest_blockhash()?;
     let mut transaction = Transaction::new_with_payer(&[init_mint_ix], Some(&payer.pubkey()));
-    transaction.sign(&[&payer, &mint_account], recent_blockhash);
+    transaction.sign(&[payer, &mint_account], recent_blockhash);
     client.send_and_confirm_transaction(&transaction)?;
 
-    println!("Mint initialized successfully");
+    println!("Mint initialized successfully: {}", mint_account.pubkey());
 
     // Create and send the "mint token" transaction
-    let associated_token_account = spl_associated_token_account::get_associated_token_address(
-        &payer.pubkey(),
-        &mint_account.pubkey(),
-    );
+    let associated_token_account =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            &recipient,
+            &mint_account.pubkey(),
+            &token_program_id,
+        );
     let associated_token_account_id = spl_associated_token_account::ID;
-    let data = SplTokenMint::Mint(MintToArgs { amount: 50 });
+    let data = SplTokenMint::Mint(MintToArgs {
+        amount: 50,
+        recipient,
+    });
     let mut buffer: Vec<u8> = Vec::new();
     data.serialize(&mut buffer)?;
     let mint_token_ix = Instruction::new_with_bytes(
@@ -99,17 +335,203 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             AccountMeta::new(payer.pubkey(), true),
             AccountMeta::new(associated_token_account, false),
             AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(recipient, false),
             AccountMeta::new(system_account, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new_readonly(associated_token_account_id, false),
         ],
     );
     let recent_blockhash = client.get_latest_blockhash()?;
     let mut transaction = Transaction::new_with_payer(&[mint_token_ix], Some(&payer.pubkey()));
-    transaction.sign(&[&payer], recent_blockhash);
+    transaction.sign(&[payer], recent_blockhash);
     client.send_and_confirm_transaction(&transaction)?;
 
     println!("Mint token successfully");
 
+    // If metadata fields were supplied, attach on-chain name/symbol/URI metadata to the mint.
+    if let (Some(name), Some(symbol), Some(uri)) = (name, symbol, uri) {
+        let (metadata_account, _bump) = Pubkey::find_program_address(
+            &[
+                b"metadata",
+                mpl_token_metadata::ID.as_ref(),
+                mint_account.pubkey().as_ref(),
+            ],
+            &mpl_token_metadata::ID,
+        );
+        let data = SplTokenMint::CreateMetadata(CreateMetadataArgs { name, symbol, uri });
+        let mut buffer: Vec<u8> = Vec::new();
+        data.serialize(&mut buffer)?;
+        let create_metadata_ix = Instruction::new_with_bytes(
+            program_id,
+            &buffer,
+            vec![
+                AccountMeta::new(metadata_account, false),
+                AccountMeta::new_readonly(mint_account.pubkey(), false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(payer.pubkey(), false),
+                AccountMeta::new_readonly(system_account, false),
+                AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
+                AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+            ],
+        );
+        let recent_blockhash = client.get_latest_blockhash()?;
+        let mut transaction =
+            Transaction::new_with_payer(&[create_metadata_ix], Some(&payer.pubkey()));
+        transaction.sign(&[payer], recent_blockhash);
+        client.send_and_confirm_transaction(&transaction)?;
+
+        println!("Metadata created successfully");
+    }
+
+    Ok(())
+}
+
+/// Freeze or thaw `owner`'s associated token account for `mint`, with `payer` funding the
+/// transaction fee and `authority` signing as the mint's freeze authority (they may be the
+/// same keypair, but don't have to be).
+#[allow(clippy::too_many_arguments)]
+fn set_frozen_state(
+    client: &RpcClient,
+    program_id: Pubkey,
+    payer: &Keypair,
+    authority: &Keypair,
+    token_program_id: Pubkey,
+    mint: Pubkey,
+    owner: Pubkey,
+    freeze: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let associated_token_account =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            &owner,
+            &mint,
+            &token_program_id,
+        );
+
+    let data = if freeze {
+        SplTokenMint::Freeze
+    } else {
+        SplTokenMint::Thaw
+    };
+    let mut buffer: Vec<u8> = Vec::new();
+    data.serialize(&mut buffer)?;
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &buffer,
+        vec![
+            AccountMeta::new(associated_token_account, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(token_program_id, false),
+        ],
+    );
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&payer.pubkey()));
+    if authority.pubkey() == payer.pubkey() {
+        transaction.sign(&[payer], recent_blockhash);
+    } else {
+        transaction.sign(&[payer, authority], recent_blockhash);
+    }
+    client.send_and_confirm_transaction(&transaction)?;
+
+    println!(
+        "{} successfully",
+        if freeze { "Frozen" } else { "Thawed" }
+    );
+
+    Ok(())
+}
+
+/// Transfer `amount` tokens of `mint` from the payer's associated token account to `to`'s,
+/// creating the destination account if it doesn't exist yet.
+#[allow(clippy::too_many_arguments)]
+fn transfer(
+    client: &RpcClient,
+    program_id: Pubkey,
+    payer: &Keypair,
+    token_program_id: Pubkey,
+    mint: Pubkey,
+    to: Pubkey,
+    amount: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let source_account = spl_associated_token_account::get_associated_token_address_with_program_id(
+        &payer.pubkey(),
+        &mint,
+        &token_program_id,
+    );
+    let destination_account =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            &to,
+            &mint,
+            &token_program_id,
+        );
+    let associated_token_account_id = spl_associated_token_account::ID;
+
+    let data = SplTokenMint::Transfer(TransferArgs { amount });
+    let mut buffer: Vec<u8> = Vec::new();
+    data.serialize(&mut buffer)?;
+    let transfer_ix = Instruction::new_with_bytes(
+        program_id,
+        &buffer,
+        vec![
+            AccountMeta::new(source_account, false),
+            AccountMeta::new(destination_account, false),
+            AccountMeta::new_readonly(to, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(payer.pubkey(), true),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(token_program_id, false),
+            AccountMeta::new_readonly(associated_token_account_id, false),
+        ],
+    );
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let mut transaction = Transaction::new_with_payer(&[transfer_ix], Some(&payer.pubkey()));
+    transaction.sign(&[payer], recent_blockhash);
+    client.send_and_confirm_transaction(&transaction)?;
+
+    println!("Transferred {} tokens to {} successfully", amount, to);
+
+    Ok(())
+}
+
+/// Burn `amount` tokens of `mint` from the payer's associated token account.
+fn burn(
+    client: &RpcClient,
+    program_id: Pubkey,
+    payer: &Keypair,
+    token_program_id: Pubkey,
+    mint: Pubkey,
+    amount: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let token_account = spl_associated_token_account::get_associated_token_address_with_program_id(
+        &payer.pubkey(),
+        &mint,
+        &token_program_id,
+    );
+
+    let data = SplTokenMint::Burn(BurnArgs { amount });
+    let mut buffer: Vec<u8> = Vec::new();
+    data.serialize(&mut buffer)?;
+    let burn_ix = Instruction::new_with_bytes(
+        program_id,
+        &buffer,
+        vec![
+            AccountMeta::new(token_account, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(payer.pubkey(), true),
+            AccountMeta::new_readonly(token_program_id, false),
+        ],
+    );
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+    let mut transaction = Transaction::new_with_payer(&[burn_ix], Some(&payer.pubkey()));
+    transaction.sign(&[payer], recent_blockhash);
+    client.send_and_confirm_transaction(&transaction)?;
+
+    println!("Burned {} tokens successfully", amount);
+
     Ok(())
 }
@@ -1,4 +1,6 @@
 use borsh::BorshDeserialize;
+use mpl_token_metadata::instructions::{CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs};
+use mpl_token_metadata::types::DataV2;
 use solana_program::program_pack::Pack;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
@@ -6,12 +8,14 @@ use solana_program::{
     entrypoint::ProgramResult,
     msg,
     program::invoke,
+    program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
     system_instruction,
     sysvar::Sysvar,
 };
 use spl_token::{instruction as token_instruction, state::Mint};
+use spl_token_2022::extension::{transfer_fee, ExtensionType, StateWithExtensions};
 
 // Entrypoint of the Solana program. This is where Solana starts executing the program.
 entrypoint!(process_instruction);
@@ -20,16 +24,48 @@ entrypoint!(process_instruction);
 enum SplTokenMint {
     Initialize(InitializeMintArgs),
     Mint(MintToArgs),
+    CreateMetadata(CreateMetadataArgs),
+    Freeze,
+    Thaw,
+    Transfer(TransferArgs),
+    Burn(BurnArgs),
 }
 
 #[derive(BorshDeserialize, Debug)]
 struct InitializeMintArgs {
     decimals: u8,
+    freeze_authority: Option<Pubkey>,
+    /// Only meaningful (and only usable) when the mint is created with the Token-2022 program.
+    transfer_fee: Option<TransferFeeArgs>,
+}
+
+#[derive(BorshDeserialize, Debug)]
+struct TransferFeeArgs {
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
 }
 
 #[derive(BorshDeserialize, Debug)]
 struct MintToArgs {
     amount: u64,
+    recipient: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug)]
+struct CreateMetadataArgs {
+    name: String,
+    symbol: String,
+    uri: String,
+}
+
+#[derive(BorshDeserialize, Debug)]
+struct TransferArgs {
+    amount: u64,
+}
+
+#[derive(BorshDeserialize, Debug)]
+struct BurnArgs {
+    amount: u64,
 }
 
 /// Main process instruction function, which dispatches different instructions based on `instruction_data`.
@@ -50,6 +86,11 @@ fn process_instruction(
     match instruction_data {
         SplTokenMint::Initialize(data) => initialize_mint(accounts, data),
         SplTokenMint::Mint(data) => mint_token(accounts, data),
+        SplTokenMint::CreateMetadata(data) => create_metadata(accounts, data),
+        SplTokenMint::Freeze => freeze(accounts),
+        SplTokenMint::Thaw => thaw(accounts),
+        SplTokenMint::Transfer(data) => transfer(accounts, data),
+        SplTokenMint::Burn(data) => burn(accounts, data),
     }
 }
 
@@ -73,6 +114,26 @@ fn initialize_mint(accounts: &[AccountInfo], data: InitializeMintArgs) -> Progra
     let rent_sysvar = next_account_info(accounts_iter)?;
 
     let decimals = data.decimals;
+    let freeze_authority = data.freeze_authority.as_ref();
+
+    // Dispatch on the passed-in token program's account key rather than assuming classic
+    // SPL Token, so callers can opt into Token-2022 (and its mint extensions) simply by
+    // passing `spl_token_2022::id()` as the token program.
+    let is_token_2022 = *token_program.key == spl_token_2022::id();
+    if data.transfer_fee.is_some() && !is_token_2022 {
+        msg!("transfer_fee requires the Token-2022 program");
+        return Err(ProgramError::InvalidArgument);
+    }
+    let extension_types: &[ExtensionType] = if data.transfer_fee.is_some() {
+        &[ExtensionType::TransferFeeConfig]
+    } else {
+        &[]
+    };
+    let mint_len = if is_token_2022 {
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(extension_types)?
+    } else {
+        Mint::LEN
+    };
 
     // Step 1:
     // The Authority Account initiates the creation of the Mint Account.
@@ -82,8 +143,8 @@ fn initialize_mint(accounts: &[AccountInfo], data: InitializeMintArgs) -> Progra
         &system_instruction::create_account(
             authority_account.key,
             mint_account.key,
-            Rent::get()?.minimum_balance(Mint::LEN),
-            Mint::LEN as u64,
+            Rent::get()?.minimum_balance(mint_len),
+            mint_len as u64,
             token_program.key,
         ),
         &[
@@ -94,18 +155,49 @@ fn initialize_mint(accounts: &[AccountInfo], data: InitializeMintArgs) -> Progra
         ],
     )?;
 
-    // Step 2: The Mint Account is then initialized with the Token Program.
+    // Step 2: Extensions must be initialized before `initialize_mint` is called.
+    if let Some(TransferFeeArgs {
+        transfer_fee_basis_points,
+        maximum_fee,
+    }) = data.transfer_fee
+    {
+        msg!("Initializing transfer fee config extension");
+        invoke(
+            &transfer_fee::instruction::initialize_transfer_fee_config(
+                token_program.key,
+                mint_account.key,
+                Some(authority_account.key),
+                Some(authority_account.key),
+                transfer_fee_basis_points,
+                maximum_fee,
+            )?,
+            &[mint_account.clone(), token_program.clone()],
+        )?;
+    }
+
+    // Step 3: The Mint Account is then initialized with the Token Program.
     // This sets important properties, such as the number of decimal places
     // (for divisibility) and the authority that controls minting.
     msg!("Initializing mint account ({})", mint_account.key);
-    invoke(
-        &token_instruction::initialize_mint(
+    let initialize_mint_ix = if is_token_2022 {
+        spl_token_2022::instruction::initialize_mint(
+            token_program.key,
+            mint_account.key,
+            authority_account.key,
+            freeze_authority,
+            decimals,
+        )?
+    } else {
+        token_instruction::initialize_mint(
             token_program.key,
             mint_account.key,
             authority_account.key,
-            None,
+            freeze_authority,
             decimals,
-        )?,
+        )?
+    };
+    invoke(
+        &initialize_mint_ix,
         &[
             mint_account.clone(),
             rent_sysvar.clone(),
@@ -121,8 +213,8 @@ fn initialize_mint(accounts: &[AccountInfo], data: InitializeMintArgs) -> Progra
 ///
 /// # Parameters
 /// - `program_id`: The program's public key.
-/// - `accounts`: The accounts needed for minting (mint account, authority account, associated_token_account, payer, token program and associated_token_program).
-/// - `data`: The input data specifying MintTo parameters (like amount).
+/// - `accounts`: The accounts needed for minting (mint account, authority account, associated_token_account, payer, recipient, system program, token program and associated_token_program).
+/// - `data`: The input data specifying MintTo parameters (like amount and recipient).
 ///
 /// # Returns
 /// - `ProgramResult`: Returns `Ok(())` if successful, or an error if something goes wrong.
@@ -134,6 +226,7 @@ fn mint_token(accounts: &[AccountInfo], data: MintToArgs) -> ProgramResult {
     let authority_account = next_account_info(accounts_iter)?;
     let associated_token_account = next_account_info(accounts_iter)?;
     let payer = next_account_info(accounts_iter)?;
+    let recipient = next_account_info(accounts_iter)?;
     let system_program = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
     let associated_token_program = next_account_info(accounts_iter)?;
@@ -141,14 +234,31 @@ fn mint_token(accounts: &[AccountInfo], data: MintToArgs) -> ProgramResult {
     let amount = data.amount;
     msg!("Mint {} tokens on account {}", amount, mint_account.key);
 
-    // Step 1: The User Wallet checks if an Associated Token Account exists to hold tokens
-    // of this specific mint. If not, the program creates this associated token account.
+    // The associated token account the caller passed in must be the one derived from the
+    // recipient and the mint, so tokens can't be minted into an unrelated account.
+    let expected_associated_token_account = spl_associated_token_account::get_associated_token_address_with_program_id(
+        &data.recipient,
+        mint_account.key,
+        token_program.key,
+    );
+    if expected_associated_token_account != *associated_token_account.key {
+        msg!("Associated token account does not match the derived address for the recipient");
+        return Err(ProgramError::InvalidArgument);
+    }
+    if data.recipient != *recipient.key {
+        msg!("Recipient account does not match the recipient in the instruction data");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Step 1: The Recipient Wallet checks if an Associated Token Account exists to hold tokens
+    // of this specific mint. If not, the program creates this associated token account, with
+    // `payer` funding the rent and `recipient` owning the resulting account.
     if associated_token_account.lamports() == 0 {
         msg!("Creating associated token account");
         invoke(
             &spl_associated_token_account::instruction::create_associated_token_account(
                 payer.key,
-                payer.key,
+                recipient.key,
                 mint_account.key,
                 token_program.key,
             ),
@@ -156,6 +266,7 @@ fn mint_token(accounts: &[AccountInfo], data: MintToArgs) -> ProgramResult {
                 mint_account.clone(),
                 associated_token_account.clone(),
                 payer.clone(),
+                recipient.clone(),
                 system_program.clone(),
                 token_program.clone(),
                 associated_token_program.clone(),
@@ -167,15 +278,27 @@ fn mint_token(accounts: &[AccountInfo], data: MintToArgs) -> ProgramResult {
 
     // Step 2: The Mint Account mints a specified number of tokens and deposits
     // them into the Associated Token Account.
-    invoke(
-        &token_instruction::mint_to(
+    let mint_to_ix = if *token_program.key == spl_token_2022::id() {
+        spl_token_2022::instruction::mint_to(
+            token_program.key,
+            mint_account.key,
+            associated_token_account.key,
+            authority_account.key,
+            &[authority_account.key],
+            amount,
+        )?
+    } else {
+        token_instruction::mint_to(
             token_program.key,
             mint_account.key,
             associated_token_account.key,
             authority_account.key,
             &[authority_account.key],
             amount,
-        )?,
+        )?
+    };
+    invoke(
+        &mint_to_ix,
         &[
             mint_account.clone(),
             authority_account.clone(),
@@ -191,3 +314,547 @@ fn mint_token(accounts: &[AccountInfo], data: MintToArgs) -> ProgramResult {
     );
     Ok(())
 }
+
+/// Attach on-chain name/symbol/URI metadata to a mint via Metaplex's token-metadata program.
+///
+/// # Parameters
+/// - `accounts`: The accounts needed to create the metadata (metadata PDA, mint, mint authority,
+///   payer, update authority, system program, rent sysvar, and the metadata program).
+/// - `data`: The input data specifying the metadata (name, symbol, URI).
+///
+/// # Returns
+/// - `ProgramResult`: Returns `Ok(())` if successful, or an error if something goes wrong.
+fn create_metadata(accounts: &[AccountInfo], data: CreateMetadataArgs) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Retrieve the necessary accounts from the `accounts` slice.
+    let metadata_account = next_account_info(accounts_iter)?;
+    let mint_account = next_account_info(accounts_iter)?;
+    let mint_authority = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let update_authority = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let rent_sysvar = next_account_info(accounts_iter)?;
+    let metadata_program = next_account_info(accounts_iter)?;
+
+    // Derive the metadata PDA and make sure the caller supplied the right one.
+    let (metadata_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            mint_account.key.as_ref(),
+        ],
+        &mpl_token_metadata::ID,
+    );
+    if metadata_pda != *metadata_account.key {
+        msg!("Metadata account does not match the derived PDA");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    msg!("Creating metadata account ({})", metadata_account.key);
+    let create_metadata_ix = CreateMetadataAccountV3 {
+        metadata: *metadata_account.key,
+        mint: *mint_account.key,
+        mint_authority: *mint_authority.key,
+        payer: *payer.key,
+        update_authority: (*update_authority.key, true),
+        system_program: solana_program::system_program::ID,
+        rent: Some(*rent_sysvar.key),
+    }
+    .instruction(CreateMetadataAccountV3InstructionArgs {
+        data: DataV2 {
+            name: data.name,
+            symbol: data.symbol,
+            uri: data.uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        is_mutable: true,
+        collection_details: None,
+    });
+
+    invoke(
+        &create_metadata_ix,
+        &[
+            metadata_account.clone(),
+            mint_account.clone(),
+            mint_authority.clone(),
+            payer.clone(),
+            update_authority.clone(),
+            system_program.clone(),
+            rent_sysvar.clone(),
+            metadata_program.clone(),
+        ],
+    )?;
+
+    msg!("Metadata created successfully");
+    Ok(())
+}
+
+/// Freeze an associated token account, preventing it from transferring or burning tokens.
+///
+/// # Parameters
+/// - `accounts`: The accounts needed to freeze the account (target associated token account,
+///   mint, freeze authority, and token program).
+///
+/// # Returns
+/// - `ProgramResult`: Returns `Ok(())` if successful, or an error if something goes wrong.
+fn freeze(accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Retrieve the necessary accounts from the `accounts` slice.
+    let associated_token_account = next_account_info(accounts_iter)?;
+    let mint_account = next_account_info(accounts_iter)?;
+    let freeze_authority = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    msg!("Freezing account {}", associated_token_account.key);
+    let freeze_ix = if *token_program.key == spl_token_2022::id() {
+        spl_token_2022::instruction::freeze_account(
+            token_program.key,
+            associated_token_account.key,
+            mint_account.key,
+            freeze_authority.key,
+            &[freeze_authority.key],
+        )?
+    } else {
+        token_instruction::freeze_account(
+            token_program.key,
+            associated_token_account.key,
+            mint_account.key,
+            freeze_authority.key,
+            &[freeze_authority.key],
+        )?
+    };
+    invoke(
+        &freeze_ix,
+        &[
+            associated_token_account.clone(),
+            mint_account.clone(),
+            freeze_authority.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    msg!("Account frozen successfully");
+    Ok(())
+}
+
+/// Thaw a previously frozen associated token account.
+///
+/// # Parameters
+/// - `accounts`: The accounts needed to thaw the account (target associated token account,
+///   mint, freeze authority, and token program).
+///
+/// # Returns
+/// - `ProgramResult`: Returns `Ok(())` if successful, or an error if something goes wrong.
+fn thaw(accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Retrieve the necessary accounts from the `accounts` slice.
+    let associated_token_account = next_account_info(accounts_iter)?;
+    let mint_account = next_account_info(accounts_iter)?;
+    let freeze_authority = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    msg!("Thawing account {}", associated_token_account.key);
+    let thaw_ix = if *token_program.key == spl_token_2022::id() {
+        spl_token_2022::instruction::thaw_account(
+            token_program.key,
+            associated_token_account.key,
+            mint_account.key,
+            freeze_authority.key,
+            &[freeze_authority.key],
+        )?
+    } else {
+        token_instruction::thaw_account(
+            token_program.key,
+            associated_token_account.key,
+            mint_account.key,
+            freeze_authority.key,
+            &[freeze_authority.key],
+        )?
+    };
+    invoke(
+        &thaw_ix,
+        &[
+            associated_token_account.clone(),
+            mint_account.clone(),
+            freeze_authority.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    msg!("Account thawed successfully");
+    Ok(())
+}
+
+/// Transfer tokens from one associated token account to another, creating the destination
+/// account if it doesn't exist yet.
+///
+/// # Parameters
+/// - `accounts`: The accounts needed to transfer tokens (source ATA, destination ATA,
+///   destination wallet owner, mint, owner, payer, system program, token program, and
+///   associated token program).
+/// - `data`: The input data specifying TransferArgs (like amount).
+///
+/// # Returns
+/// - `ProgramResult`: Returns `Ok(())` if successful, or an error if something goes wrong.
+fn transfer(accounts: &[AccountInfo], data: TransferArgs) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Retrieve the necessary accounts from the `accounts` slice.
+    let source_account = next_account_info(accounts_iter)?;
+    let destination_account = next_account_info(accounts_iter)?;
+    let destination_owner = next_account_info(accounts_iter)?;
+    let mint_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let system_program = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+    let associated_token_program = next_account_info(accounts_iter)?;
+
+    let amount = data.amount;
+
+    // Mirror mint_token's ATA-creation logic: if the destination doesn't exist yet, create it
+    // with `payer` funding rent and `destination_owner` as the owner.
+    if destination_account.lamports() == 0 {
+        msg!("Creating destination associated token account");
+        invoke(
+            &spl_associated_token_account::instruction::create_associated_token_account(
+                payer.key,
+                destination_owner.key,
+                mint_account.key,
+                token_program.key,
+            ),
+            &[
+                mint_account.clone(),
+                destination_account.clone(),
+                payer.clone(),
+                destination_owner.clone(),
+                system_program.clone(),
+                token_program.clone(),
+                associated_token_program.clone(),
+            ],
+        )?;
+    } else {
+        msg!("Destination associated token account exists");
+    }
+
+    // Dispatch on the token program's key, same as `initialize_mint`: a Token-2022 mint with
+    // any extension (e.g. the `transfer_fee` extension from chunk0-2) is sized larger than
+    // `Mint::LEN`, so the classic `Pack::unpack` rejects it and extension-aware unpacking is
+    // required to read its decimals.
+    let is_token_2022 = *token_program.key == spl_token_2022::id();
+    let decimals = if is_token_2022 {
+        let mint_data = mint_account.data.borrow();
+        let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        mint_state.base.decimals
+    } else {
+        Mint::unpack(&mint_account.data.borrow())?.decimals
+    };
+
+    msg!(
+        "Transferring {} tokens from {} to {}",
+        amount,
+        source_account.key,
+        destination_account.key
+    );
+    let transfer_ix = if is_token_2022 {
+        spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            source_account.key,
+            mint_account.key,
+            destination_account.key,
+            owner.key,
+            &[owner.key],
+            amount,
+            decimals,
+        )?
+    } else {
+        token_instruction::transfer_checked(
+            token_program.key,
+            source_account.key,
+            mint_account.key,
+            destination_account.key,
+            owner.key,
+            &[owner.key],
+            amount,
+            decimals,
+        )?
+    };
+    invoke(
+        &transfer_ix,
+        &[
+            source_account.clone(),
+            mint_account.clone(),
+            destination_account.clone(),
+            owner.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    msg!("Transferred {} tokens successfully", amount);
+    Ok(())
+}
+
+/// Burn tokens from an associated token account, reducing both its balance and the mint supply.
+///
+/// # Parameters
+/// - `accounts`: The accounts needed to burn tokens (token account, mint, owner, token program).
+/// - `data`: The input data specifying BurnArgs (like amount).
+///
+/// # Returns
+/// - `ProgramResult`: Returns `Ok(())` if successful, or an error if something goes wrong.
+fn burn(accounts: &[AccountInfo], data: BurnArgs) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Retrieve the necessary accounts from the `accounts` slice.
+    let token_account = next_account_info(accounts_iter)?;
+    let mint_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    let amount = data.amount;
+    msg!("Burning {} tokens from {}", amount, token_account.key);
+    let burn_ix = if *token_program.key == spl_token_2022::id() {
+        spl_token_2022::instruction::burn(
+            token_program.key,
+            token_account.key,
+            mint_account.key,
+            owner.key,
+            &[owner.key],
+            amount,
+        )?
+    } else {
+        token_instruction::burn(
+            token_program.key,
+            token_account.key,
+            mint_account.key,
+            owner.key,
+            &[owner.key],
+            amount,
+        )?
+    };
+    invoke(
+        &burn_ix,
+        &[
+            token_account.clone(),
+            mint_account.clone(),
+            owner.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    msg!("Burned {} tokens successfully", amount);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::instruction::{AccountMeta, Instruction};
+    use solana_program::program_option::COption;
+    use solana_program_test::{processor, ProgramTest};
+    use solana_sdk::{
+        signature::{Keypair, Signer},
+        system_program,
+        transaction::Transaction,
+    };
+
+    fn program_test() -> (Pubkey, ProgramTest) {
+        let program_id = Pubkey::new_unique();
+        let program_test = ProgramTest::new(
+            "spl_token_mint",
+            program_id,
+            processor!(process_instruction),
+        );
+        (program_id, program_test)
+    }
+
+    fn initialize_mint_ix(
+        program_id: Pubkey,
+        mint: Pubkey,
+        authority: Pubkey,
+        decimals: u8,
+    ) -> Instruction {
+        let data = SplTokenMint::Initialize(InitializeMintArgs {
+            decimals,
+            freeze_authority: None,
+            transfer_fee: None,
+        });
+        Instruction::new_with_borsh(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(mint, true),
+                AccountMeta::new(authority, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(solana_program::sysvar::rent::id(), false),
+            ],
+        )
+    }
+
+    fn mint_to_ix(
+        program_id: Pubkey,
+        mint: Pubkey,
+        authority: Pubkey,
+        associated_token_account: Pubkey,
+        payer: Pubkey,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let data = SplTokenMint::Mint(MintToArgs { amount, recipient });
+        Instruction::new_with_borsh(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(mint, false),
+                AccountMeta::new(authority, true),
+                AccountMeta::new(associated_token_account, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(recipient, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            ],
+        )
+    }
+
+    #[tokio::test]
+    async fn initialize_and_mint_succeeds() {
+        let (program_id, program_test) = program_test();
+        let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+        let mint = Keypair::new();
+        let init_ix = initialize_mint_ix(program_id, mint.pubkey(), payer.pubkey(), 2);
+        let mut tx = Transaction::new_with_payer(&[init_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &mint], recent_blockhash);
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let mint_account = banks_client
+            .get_account(mint.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        let mint_state = Mint::unpack(&mint_account.data).unwrap();
+        assert_eq!(mint_state.decimals, 2);
+        assert_eq!(mint_state.mint_authority, COption::Some(payer.pubkey()));
+
+        let associated_token_account =
+            spl_associated_token_account::get_associated_token_address(&payer.pubkey(), &mint.pubkey());
+        let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+        let mint_to_ix = mint_to_ix(
+            program_id,
+            mint.pubkey(),
+            payer.pubkey(),
+            associated_token_account,
+            payer.pubkey(),
+            payer.pubkey(),
+            50,
+        );
+        let mut tx = Transaction::new_with_payer(&[mint_to_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], recent_blockhash);
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let token_account = banks_client
+            .get_account(associated_token_account)
+            .await
+            .unwrap()
+            .unwrap();
+        let token_state = spl_token::state::Account::unpack(&token_account.data).unwrap();
+        assert_eq!(token_state.amount, 50);
+    }
+
+    #[tokio::test]
+    async fn mint_with_wrong_account_order_fails() {
+        let (program_id, program_test) = program_test();
+        let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+        let mint = Keypair::new();
+        let init_ix = initialize_mint_ix(program_id, mint.pubkey(), payer.pubkey(), 2);
+        let mut tx = Transaction::new_with_payer(&[init_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &mint], recent_blockhash);
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let associated_token_account =
+            spl_associated_token_account::get_associated_token_address(&payer.pubkey(), &mint.pubkey());
+        let data = SplTokenMint::Mint(MintToArgs {
+            amount: 50,
+            recipient: payer.pubkey(),
+        });
+        // Swap the mint and authority accounts so the processor reads the wrong keys.
+        let bad_ix = Instruction::new_with_borsh(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(mint.pubkey(), false),
+                AccountMeta::new(associated_token_account, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(payer.pubkey(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+                AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            ],
+        );
+        let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+        let mut tx = Transaction::new_with_payer(&[bad_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer], recent_blockhash);
+        let result = banks_client.process_transaction(tx).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn mint_with_non_authority_signer_fails() {
+        let (program_id, program_test) = program_test();
+        let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+        let mint = Keypair::new();
+        let init_ix = initialize_mint_ix(program_id, mint.pubkey(), payer.pubkey(), 2);
+        let mut tx = Transaction::new_with_payer(&[init_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &mint], recent_blockhash);
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let impostor = Keypair::new();
+        let associated_token_account =
+            spl_associated_token_account::get_associated_token_address(&payer.pubkey(), &mint.pubkey());
+        let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+        let mint_to_ix = mint_to_ix(
+            program_id,
+            mint.pubkey(),
+            impostor.pubkey(),
+            associated_token_account,
+            payer.pubkey(),
+            payer.pubkey(),
+            50,
+        );
+        let mut tx = Transaction::new_with_payer(&[mint_to_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &impostor], recent_blockhash);
+        let result = banks_client.process_transaction(tx).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn zero_decimal_mint_succeeds() {
+        let (program_id, program_test) = program_test();
+        let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+        let mint = Keypair::new();
+        let init_ix = initialize_mint_ix(program_id, mint.pubkey(), payer.pubkey(), 0);
+        let mut tx = Transaction::new_with_payer(&[init_ix], Some(&payer.pubkey()));
+        tx.sign(&[&payer, &mint], recent_blockhash);
+        banks_client.process_transaction(tx).await.unwrap();
+
+        let mint_account = banks_client
+            .get_account(mint.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        let mint_state = Mint::unpack(&mint_account.data).unwrap();
+        assert_eq!(mint_state.decimals, 0);
+    }
+}